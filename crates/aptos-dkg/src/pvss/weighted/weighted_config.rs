@@ -11,6 +11,200 @@ use rand_core::{CryptoRng, RngCore};
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 
+/// A binary indexed (Fenwick) tree over player weights, used by
+/// [`WeightedConfig::get_random_eligible_subset_of_players_fenwick`] to sample a player with
+/// probability proportional to its weight, and to exclude a picked player from future draws, in
+/// O(log n) time rather than the O(n) time of a linear scan. `WeightedConfig` also keeps one of
+/// these around as a cache of its *live* (i.e., not-yet-[`WeightedConfig::remove_player`]-ed)
+/// weights, so that [`WeightedConfig::remove_player`], [`WeightedConfig::set_player_weight`] and
+/// [`WeightedConfig::add_player`] can all update it in O(log n) rather than rebuilding it from
+/// scratch.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct FenwickTree {
+    /// 1-indexed internal array: `tree[i]` stores the sum of weights over a range of (0-indexed)
+    /// players ending at player `i - 1`.
+    tree: Vec<usize>,
+    /// The number of players, i.e., `tree.len() - 1`.
+    n: usize,
+}
+
+impl FenwickTree {
+    /// Builds a Fenwick tree over `weights`, indexed by (0-indexed) player ID.
+    fn new(weights: &[usize]) -> Self {
+        let n = weights.len();
+        let mut tree = vec![0usize; n + 1];
+
+        for (i, w) in weights.iter().enumerate() {
+            Self::add(&mut tree, i + 1, *w);
+        }
+
+        FenwickTree { tree, n }
+    }
+
+    /// Adds `delta` to the 1-indexed leaf `i` and all of its ancestors.
+    fn add(tree: &mut [usize], mut i: usize, delta: usize) {
+        let n = tree.len() - 1;
+        while i <= n {
+            tree[i] += delta;
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// Point-updates the (0-indexed) player `idx`'s weight in the tree, subtracting `delta` from
+    /// it so that it contributes less (or nothing) to future draws.
+    fn remove_weight(&mut self, idx: usize, delta: usize) {
+        let mut i = idx + 1;
+        while i <= self.n {
+            self.tree[i] -= delta;
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// Point-updates the (0-indexed) player `idx`'s weight in the tree, adding `delta` to it.
+    fn add_weight(&mut self, idx: usize, delta: usize) {
+        Self::add(&mut self.tree, idx + 1, delta);
+    }
+
+    /// Grows the tree by one (0-indexed) slot, for a new player with weight `weight`.
+    ///
+    /// The new (1-indexed) node `x = self.n` may cover more than just the new leaf: in a Fenwick
+    /// tree, node `x` holds the sum over the range `(x - lowbit(x), x]`, so if `lowbit(x) > 1` it
+    /// also covers some of the *existing* leaves immediately before it. Those are folded in by
+    /// walking down from `x - 1` the same way a range-sum query would, reusing the (already
+    /// correct) sums of the pre-existing tree, rather than by a bare point-update.
+    fn push(&mut self, weight: usize) {
+        self.n += 1;
+        let x = self.n;
+        self.tree.push(0);
+
+        let z = x - (x & x.wrapping_neg());
+        let mut sum = weight;
+        let mut i = x - 1;
+        while i > z {
+            sum += self.tree[i];
+            i -= i & i.wrapping_neg();
+        }
+
+        self.tree[x] = sum;
+    }
+
+    /// Returns the sum of the weights of players `0..=i` (0-indexed, inclusive).
+    fn prefix_sum(&self, i: usize) -> usize {
+        let mut i = i + 1;
+        let mut sum = 0;
+        while i > 0 {
+            sum += self.tree[i];
+            i -= i & i.wrapping_neg();
+        }
+        sum
+    }
+
+    /// The current total weight over all players, i.e., the sum of the (possibly-updated) weights
+    /// this tree was built from.
+    fn total(&self) -> usize {
+        if self.n == 0 {
+            0
+        } else {
+            self.prefix_sum(self.n - 1)
+        }
+    }
+
+    /// Finds the smallest (0-indexed) player index whose cumulative weight exceeds `x`, by binary
+    /// lifting over the tree: starting at the highest power of two `<= n` and descending,
+    /// accumulating the cumulative weight seen so far.
+    fn find(&self, mut x: usize) -> usize {
+        let mut pos = 0;
+        let mut log = 1usize << (usize::BITS - 1 - self.n.leading_zeros());
+
+        while log > 0 {
+            let next = pos + log;
+            if next <= self.n && self.tree[next] <= x {
+                pos = next;
+                x -= self.tree[next];
+            }
+            log >>= 1;
+        }
+
+        pos
+    }
+}
+
+/// A precomputed sampler for drawing player indices with probability proportional to their
+/// weight in O(1) time, using Vose's version of Walker's alias method. Building the sampler takes
+/// O(n) time; once built, it can be reused to draw as many samples as needed, which is preferable
+/// to [`FenwickTree`]-based sampling when the same (immutable) distribution of weights is going to
+/// be sampled many times, e.g., in benchmarks or liveness simulations.
+#[derive(Clone, Debug)]
+pub struct AliasSampler {
+    /// `prob[i]` is the probability with which index `i` is returned directly, rather than its
+    /// alias `alias[i]`.
+    prob: Vec<f64>,
+    /// `alias[i]` is the index returned when the coin flip for index `i` comes up tails.
+    alias: Vec<usize>,
+}
+
+impl AliasSampler {
+    /// Builds an alias sampler over `weights` via Vose's algorithm: each weight is scaled so that
+    /// the mean probability is 1 (i.e., `p_i = weight_i * n / W`), and the resulting indices are
+    /// partitioned into a "small" stack (`p_i < 1`) and a "large" stack (`p_i >= 1`). Indices are
+    /// then popped off in small/large pairs: `prob[small] = p_small` and `alias[small] = large`
+    /// are set, `large`'s probability is decremented by `1 - p_small`, and `large` is re-filed
+    /// into the small or large stack depending on its new probability. Leftover entries (due to
+    /// floating-point rounding) get `prob = 1`.
+    pub fn new(weights: &[usize]) -> Self {
+        let n = weights.len();
+        let total_weight: usize = weights.iter().sum();
+
+        let mut prob = vec![0f64; n];
+        let mut alias = vec![0usize; n];
+        let mut scaled = weights
+            .iter()
+            .map(|w| (*w as f64) * (n as f64) / (total_weight as f64))
+            .collect::<Vec<f64>>();
+
+        let (mut small, mut large): (Vec<usize>, Vec<usize>) =
+            (0..n).partition(|i| scaled[*i] < 1.0);
+
+        // NOTE: this must check emptiness *before* popping rather than pattern-matching on
+        // `(small.pop(), large.pop())`, since the latter evaluates both pops unconditionally and
+        // would discard the one leftover item that Vose's algorithm is designed to leave behind
+        // once one of the two stacks empties out.
+        while !small.is_empty() && !large.is_empty() {
+            let s = small.pop().unwrap();
+            let l = large.pop().unwrap();
+
+            prob[s] = scaled[s];
+            alias[s] = l;
+
+            scaled[l] -= 1.0 - scaled[s];
+
+            if scaled[l] < 1.0 {
+                small.push(l);
+            } else {
+                large.push(l);
+            }
+        }
+
+        // Any leftovers are only off from 1 by floating-point rounding error.
+        for i in large.into_iter().chain(small) {
+            prob[i] = 1.0;
+        }
+
+        AliasSampler { prob, alias }
+    }
+
+    /// Draws a single index in `[0, n)` in O(1) time, with probability proportional to its weight.
+    fn sample<R: RngCore>(&self, rng: &mut R) -> usize {
+        let i = rng.gen_range(0, self.prob.len());
+
+        if rng.gen::<f64>() < self.prob[i] {
+            i
+        } else {
+            self.alias[i]
+        }
+    }
+}
+
 /// Encodes the *threshold configuration* for a *weighted* PVSS: i.e., the minimum weight $w$ and
 /// the total weight $W$ such that any subset of players with weight $\ge w$ can reconstruct a
 /// dealt secret given a PVSS transcript.
@@ -29,6 +223,15 @@ pub struct WeightedConfig {
     starting_index: Vec<usize>,
     /// The maximum weight of any player.
     max_player_weight: usize,
+    /// Whether each player (indexed by player ID) is still *live*. A player that has been
+    /// [`Self::remove_player`]-ed keeps its slot in `weight` and `starting_index` (so the share
+    /// layout other players depend on never moves), but is marked not-live here and excluded from
+    /// sampling and reconstruction.
+    live: Vec<bool>,
+    /// A cache of the *live* weights (i.e., `weight[i]` if `live[i]`, else 0), kept as a Fenwick
+    /// tree so that [`Self::remove_player`], [`Self::set_player_weight`] and [`Self::add_player`]
+    /// can all update it in O(log n) instead of rebuilding it from scratch.
+    live_weight_fenwick: FenwickTree,
 }
 
 impl WeightedConfig {
@@ -70,12 +273,15 @@ impl WeightedConfig {
         }
 
         let tc = ThresholdConfig::new(threshold_weight, W)?;
+        let live_weight_fenwick = FenwickTree::new(&weights);
         Ok(WeightedConfig {
             tc,
             num_players: n,
             weight: weights,
             starting_index,
             max_player_weight,
+            live: vec![true; n],
+            live_weight_fenwick,
         })
     }
 
@@ -167,11 +373,12 @@ impl WeightedConfig {
     }
 
     fn sort_players_by_weight(&self) -> Vec<(usize, usize)> {
-        // the set of remaining players that we are picking a "capable" subset from
+        // the set of remaining (live) players that we are picking a "capable" subset from
         let mut player_and_weights = self
             .weight
             .iter()
             .enumerate()
+            .filter(|(i, _)| self.live[*i])
             .map(|(i, w)| (i, *w))
             .collect::<Vec<(usize, usize)>>();
 
@@ -194,6 +401,193 @@ impl WeightedConfig {
 
         picked_players
     }
+
+    /// Like [`traits::SecretSharingConfig::get_random_eligible_subset_of_players`], but samples
+    /// each player with probability *exactly* proportional to its weight, rather than uniformly
+    /// at random among the remaining players. This is done using a binary indexed (Fenwick) tree
+    /// over the player weights: each draw picks `x` uniformly in `[0, current_sum)`, binary-lifts
+    /// over the tree to find the smallest player index whose cumulative weight exceeds `x` in
+    /// O(log n), and then point-updates the tree to subtract that player's weight so it cannot be
+    /// picked again.
+    pub fn get_random_eligible_subset_of_players_fenwick<R: RngCore>(
+        &self,
+        rng: &mut R,
+    ) -> Vec<Player> {
+        let mut tree = self.live_weight_fenwick.clone();
+        let mut current_sum = tree.total();
+
+        let mut picked_players = vec![];
+        let mut current_weight = 0;
+
+        while current_weight < self.tc.t {
+            let x = rng.gen_range(0, current_sum);
+            let idx = tree.find(x);
+            let w = self.weight[idx];
+
+            picked_players.push(self.get_player(idx));
+
+            tree.remove_weight(idx, w);
+            current_sum -= w;
+            current_weight += w;
+        }
+
+        picked_players
+    }
+
+    /// Builds an [`AliasSampler`] over this config's player weights, so that callers who need to
+    /// draw many eligible subsets from the same `WeightedConfig` (e.g., in benchmarks or liveness
+    /// simulations) can pay the O(n) alias-table setup cost once and reuse it across calls to
+    /// [`Self::get_random_eligible_subset_of_players_with_sampler`].
+    pub fn build_alias_sampler(&self) -> AliasSampler {
+        AliasSampler::new(&self.live_weights())
+    }
+
+    /// Returns `weight[i]` for each live player `i`, and 0 for each non-live (i.e.,
+    /// [`Self::remove_player`]-ed) one.
+    fn live_weights(&self) -> Vec<usize> {
+        self.weight
+            .iter()
+            .zip(self.live.iter())
+            .map(|(w, live)| if *live { *w } else { 0 })
+            .collect()
+    }
+
+    /// Like [`Self::get_random_eligible_subset_of_players_fenwick`], but draws from a precomputed
+    /// `sampler` (see [`Self::build_alias_sampler`]) in O(1) time per draw, rather than paying for
+    /// a fresh Fenwick tree on every call. Since the sampler itself is immutable, a player that
+    /// has already been picked is simply skipped and redrawn, rather than removed from the
+    /// sampler.
+    pub fn get_random_eligible_subset_of_players_with_sampler<R: RngCore>(
+        &self,
+        rng: &mut R,
+        sampler: &AliasSampler,
+    ) -> Vec<Player> {
+        let mut already_picked = vec![false; self.num_players];
+        let mut picked_players = vec![];
+        let mut current_weight = 0;
+
+        while current_weight < self.tc.t {
+            let idx = sampler.sample(rng);
+
+            if already_picked[idx] || !self.live[idx] {
+                continue;
+            }
+
+            already_picked[idx] = true;
+            picked_players.push(self.get_player(idx));
+            current_weight += self.weight[idx];
+        }
+
+        picked_players
+    }
+
+    /// Removes player `player` from the config: its weight is zeroed out in the cached live-weight
+    /// Fenwick tree in O(log n), excluding it from future calls to the eligible-subset selectors
+    /// (e.g., [`Self::get_random_eligible_subset_of_players_fenwick`],
+    /// [`traits::SecretSharingConfig::get_random_eligible_subset_of_players`]). The player's slot
+    /// in `weight` and `starting_index` is left untouched, so the share layout every other player
+    /// depends on does not move. A no-op (returning `Ok`) if `player` was already removed.
+    ///
+    /// Every eligible-subset selector assumes the live weight is always `>= self.tc.t` (this was
+    /// guaranteed for the lifetime of a [`Self::new`]-constructed config before this method
+    /// existed); removing a player that would break that invariant is rejected with an error
+    /// rather than left to panic later in an unrelated selector.
+    pub fn remove_player(&mut self, player: &Player) -> anyhow::Result<()> {
+        let idx = player.id;
+
+        if !self.live[idx] {
+            return Ok(());
+        }
+
+        let remaining_live_weight = self.live_weight_fenwick.total() - self.weight[idx];
+        if remaining_live_weight < self.tc.t {
+            return Err(anyhow!(
+                "cannot remove player {idx}: live weight would drop to {remaining_live_weight}, \
+                 below the reconstruction threshold weight {}",
+                self.tc.t
+            ));
+        }
+
+        self.live_weight_fenwick.remove_weight(idx, self.weight[idx]);
+        self.live[idx] = false;
+
+        Ok(())
+    }
+
+    /// Changes live player `player`'s weight to `new_weight`, updating the cached live-weight
+    /// Fenwick tree in O(log n) rather than rebuilding it. As with [`Self::remove_player`], the
+    /// player's `starting_index` and original share count (used by
+    /// [`Self::get_share_index`]/[`Self::get_virtual_player`] for reconstruction) are left as they
+    /// were at construction time; only the weight used for *sampling* changes. A no-op (returning
+    /// `Ok`) if `player` has been removed.
+    ///
+    /// As in [`Self::remove_player`], a `new_weight` that would drop the live weight below
+    /// `self.tc.t` is rejected with an error instead of silently breaking that invariant.
+    pub fn set_player_weight(&mut self, player: &Player, new_weight: usize) -> anyhow::Result<()> {
+        let idx = player.id;
+
+        if !self.live[idx] {
+            return Ok(());
+        }
+
+        let old_weight = self.weight[idx];
+        let remaining_live_weight = self.live_weight_fenwick.total() - old_weight + new_weight;
+        if remaining_live_weight < self.tc.t {
+            return Err(anyhow!(
+                "cannot set player {idx}'s weight to {new_weight}: live weight would drop to \
+                 {remaining_live_weight}, below the reconstruction threshold weight {}",
+                self.tc.t
+            ));
+        }
+
+        if new_weight >= old_weight {
+            self.live_weight_fenwick
+                .add_weight(idx, new_weight - old_weight);
+        } else {
+            self.live_weight_fenwick
+                .remove_weight(idx, old_weight - new_weight);
+        }
+
+        self.weight[idx] = new_weight;
+        self.max_player_weight = self.max_player_weight.max(new_weight);
+
+        Ok(())
+    }
+
+    /// Adds a new live player with weight `weight`, appending it after the current last player
+    /// rather than rebuilding the whole config from scratch. Since share indices are only ever
+    /// appended to (never inserted in the middle), every existing player's `starting_index` stays
+    /// stable. Returns the newly added player.
+    ///
+    /// This does grow `self.tc`'s total weight `W` (and, with it, the evaluation domain it caches)
+    /// to cover the new player's shares, which is an O(n) operation — unlike
+    /// [`Self::remove_player`]/[`Self::set_player_weight`], there's no way to extend a committee
+    /// with a reconstructible player in less than that, since the evaluation domain itself is
+    /// sized off `W`.
+    pub fn add_player(&mut self, weight: usize) -> anyhow::Result<Player> {
+        if weight == 0 {
+            return Err(anyhow!("expected the new player to have weight > 0"));
+        }
+
+        let id = self.num_players;
+
+        let new_starting_index = self
+            .starting_index
+            .last()
+            .map_or(0, |&a| a + self.weight[id - 1]);
+        let new_total_weight = self.tc.n + weight;
+        let tc = ThresholdConfig::new(self.tc.t, new_total_weight)?;
+
+        self.tc = tc;
+        self.num_players += 1;
+        self.weight.push(weight);
+        self.starting_index.push(new_starting_index);
+        self.max_player_weight = self.max_player_weight.max(weight);
+        self.live.push(true);
+        self.live_weight_fenwick.push(weight);
+
+        Ok(Player { id })
+    }
 }
 
 impl Display for WeightedConfig {
@@ -223,11 +617,12 @@ impl traits::SecretSharingConfig for WeightedConfig {
     {
         // the randomly-picked "capable" subset of players who can reconstruct the secret
         let mut picked_players = vec![];
-        // the set of remaining players that we are picking a "capable" subset from
+        // the set of remaining (live) players that we are picking a "capable" subset from
         let mut player_and_weights = self
             .weight
             .iter()
             .enumerate()
+            .filter(|(i, _)| self.live[*i])
             .map(|(i, w)| (i, *w))
             .collect::<Vec<(usize, usize)>>();
         let mut current_weight = 0;
@@ -268,7 +663,155 @@ impl traits::SecretSharingConfig for WeightedConfig {
 
 #[cfg(test)]
 mod test {
-    use crate::pvss::{traits::SecretSharingConfig, WeightedConfig};
+    use crate::pvss::{traits::SecretSharingConfig, Player, WeightedConfig};
+    use rand::thread_rng;
+    use std::collections::HashSet;
+
+    #[test]
+    fn fenwick_sampling_meets_threshold_and_stays_in_bounds() {
+        let mut rng = thread_rng();
+        let wc = WeightedConfig::new(7, vec![1, 2, 3, 4, 5]).unwrap();
+
+        for _ in 0..100 {
+            let picked = wc.get_random_eligible_subset_of_players_fenwick(&mut rng);
+            let mut seen = HashSet::new();
+
+            let total_weight: usize = picked
+                .iter()
+                .map(|p| {
+                    assert!(seen.insert(p.id), "player {} picked twice", p.id);
+                    wc.get_player_weight(p)
+                })
+                .sum();
+
+            assert!(total_weight >= wc.get_threshold_weight());
+        }
+    }
+
+    #[test]
+    fn alias_sampler_distribution_sanity() {
+        let mut rng = thread_rng();
+        // Player 4 has most of the weight, so it should be sampled directly far more often than
+        // player 0, which has almost none.
+        let wc = WeightedConfig::new(1, vec![1, 1, 1, 1, 96]).unwrap();
+        let sampler = wc.build_alias_sampler();
+
+        let mut counts = [0usize; 5];
+        let num_samples = 10_000;
+        for _ in 0..num_samples {
+            let picked = wc.get_random_eligible_subset_of_players_with_sampler(&mut rng, &sampler);
+            for p in picked {
+                counts[p.id] += 1;
+            }
+        }
+
+        assert!(counts[4] > counts[0] * 10);
+    }
+
+    #[test]
+    fn alias_sampler_handles_removed_player_zero_weight() {
+        // `live_weights()` turns a removed player's weight into 0 before handing it to
+        // `AliasSampler::new`, which is exactly the case that used to trip up the small/large
+        // pop loop: a 0-weight entry starts in `small` and, if it's the one left over once
+        // `large` empties out, it must still end up with `prob = 1.0` (irrelevant, since it's
+        // never live-eligible) rather than being silently dropped along with some other player's
+        // pairing.
+        let mut rng = thread_rng();
+        let mut wc = WeightedConfig::new(1, vec![1, 1, 1, 1, 96]).unwrap();
+        wc.remove_player(&Player { id: 4 }).unwrap();
+        let sampler = wc.build_alias_sampler();
+
+        let mut counts = [0usize; 5];
+        let num_samples = 10_000;
+        for _ in 0..num_samples {
+            let picked = wc.get_random_eligible_subset_of_players_with_sampler(&mut rng, &sampler);
+            for p in picked {
+                counts[p.id] += 1;
+            }
+        }
+
+        assert_eq!(counts[4], 0, "removed player 4 must never be sampled");
+        assert!(counts[0] > 0 && counts[1] > 0 && counts[2] > 0 && counts[3] > 0);
+    }
+
+    #[test]
+    fn add_player_matches_fresh_config_with_same_weights() {
+        // A regression test for an odd-sized tree: appending a player used to drop every
+        // pre-existing leaf's contribution from the cached Fenwick tree's ancestor sums, so
+        // `player 0`'s weight of 5 would vanish from `total()` and become unreachable by `find`.
+        let mut incremental = WeightedConfig::new(1, vec![5]).unwrap();
+        let new_player = incremental.add_player(3).unwrap();
+        assert_eq!(new_player.id, 1);
+
+        let from_scratch = WeightedConfig::new(1, vec![5, 3]).unwrap();
+        assert_eq!(
+            incremental.get_total_num_players(),
+            from_scratch.get_total_num_players()
+        );
+        assert_eq!(incremental.get_total_weight(), from_scratch.get_total_weight());
+
+        // The new player's shares must fall within the (grown) total share range, or
+        // reconstruction would be handed an out-of-bounds index.
+        for j in 0..incremental.get_player_weight(&new_player) {
+            let share_index = incremental.get_share_index(new_player.id, j).unwrap();
+            assert!(share_index < incremental.get_total_weight());
+        }
+
+        // Every player's weight should be sampleable, i.e., reachable by some draw.
+        let mut rng = thread_rng();
+        let mut seen_player_0 = false;
+        for _ in 0..100 {
+            let picked = incremental.get_random_eligible_subset_of_players_fenwick(&mut rng);
+            let total_weight: usize = picked.iter().map(|p| incremental.get_player_weight(p)).sum();
+
+            assert!(total_weight >= incremental.get_threshold_weight());
+            if picked.iter().any(|p| p.id == 0) {
+                seen_player_0 = true;
+            }
+        }
+        assert!(
+            seen_player_0,
+            "player 0's weight became unreachable after add_player"
+        );
+    }
+
+    #[test]
+    fn remove_player_excludes_from_sampling() {
+        let mut rng = thread_rng();
+        let mut wc = WeightedConfig::new(5, vec![5, 5, 5]).unwrap();
+
+        wc.remove_player(&Player { id: 0 }).unwrap();
+
+        for _ in 0..100 {
+            let picked = wc.get_random_eligible_subset_of_players_fenwick(&mut rng);
+            assert!(picked.iter().all(|p| p.id != 0));
+
+            let picked = wc.get_random_eligible_subset_of_players(&mut rng);
+            assert!(picked.iter().all(|p| p.id != 0));
+        }
+    }
+
+    #[test]
+    fn remove_player_rejects_dropping_live_weight_below_threshold() {
+        let mut wc = WeightedConfig::new(9, vec![5, 5, 5]).unwrap();
+
+        // Live weight would drop from 15 to 10, which is still >= 9, so this is allowed.
+        wc.remove_player(&Player { id: 0 }).unwrap();
+
+        // Live weight would drop further to 5, which is < 9, so this must be rejected.
+        assert!(wc.remove_player(&Player { id: 1 }).is_err());
+    }
+
+    #[test]
+    fn set_player_weight_rejects_dropping_live_weight_below_threshold() {
+        // Total weight 15, so dropping player 0's weight from 5 to 0 leaves a live weight of 10,
+        // which is below the threshold weight of 11 and must be rejected.
+        let mut wc = WeightedConfig::new(11, vec![5, 5, 5]).unwrap();
+
+        assert!(wc.set_player_weight(&Player { id: 0 }, 0).is_err());
+        // Leaves a live weight of exactly 11, which meets the threshold.
+        assert!(wc.set_player_weight(&Player { id: 0 }, 1).is_ok());
+    }
 
     #[test]
     fn bvt() {